@@ -0,0 +1,133 @@
+//! Compares `TypeSet`'s `HashMap` + pass-through-hasher backing store against a `BTreeMap<TypeId,
+//! _>` baseline equivalent to the one `TypeSet` used before this benchmark was added, to confirm
+//! the switch in src/entry.rs and src/lib.rs is actually a win for insert/lookup.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::any::{Any, TypeId};
+use std::collections::BTreeMap;
+use type_set::TypeSet;
+
+macro_rules! distinct_types {
+    ($($name:ident),*) => {
+        $(
+            #[derive(Default)]
+            struct $name(#[allow(dead_code)] u64);
+        )*
+    };
+}
+
+distinct_types!(
+    T00, T01, T02, T03, T04, T05, T06, T07, T08, T09, T10, T11, T12, T13, T14, T15, T16, T17, T18,
+    T19, T20, T21, T22, T23, T24, T25, T26, T27, T28, T29, T30, T31
+);
+
+fn fill_type_set() -> TypeSet {
+    TypeSet::new()
+        .with(T00::default())
+        .with(T01::default())
+        .with(T02::default())
+        .with(T03::default())
+        .with(T04::default())
+        .with(T05::default())
+        .with(T06::default())
+        .with(T07::default())
+        .with(T08::default())
+        .with(T09::default())
+        .with(T10::default())
+        .with(T11::default())
+        .with(T12::default())
+        .with(T13::default())
+        .with(T14::default())
+        .with(T15::default())
+        .with(T16::default())
+        .with(T17::default())
+        .with(T18::default())
+        .with(T19::default())
+        .with(T20::default())
+        .with(T21::default())
+        .with(T22::default())
+        .with(T23::default())
+        .with(T24::default())
+        .with(T25::default())
+        .with(T26::default())
+        .with(T27::default())
+        .with(T28::default())
+        .with(T29::default())
+        .with(T30::default())
+        .with(T31::default())
+}
+
+fn fill_btree_map() -> BTreeMap<TypeId, Box<dyn Any>> {
+    let mut map = BTreeMap::<TypeId, Box<dyn Any>>::new();
+    map.insert(TypeId::of::<T00>(), Box::new(T00::default()));
+    map.insert(TypeId::of::<T01>(), Box::new(T01::default()));
+    map.insert(TypeId::of::<T02>(), Box::new(T02::default()));
+    map.insert(TypeId::of::<T03>(), Box::new(T03::default()));
+    map.insert(TypeId::of::<T04>(), Box::new(T04::default()));
+    map.insert(TypeId::of::<T05>(), Box::new(T05::default()));
+    map.insert(TypeId::of::<T06>(), Box::new(T06::default()));
+    map.insert(TypeId::of::<T07>(), Box::new(T07::default()));
+    map.insert(TypeId::of::<T08>(), Box::new(T08::default()));
+    map.insert(TypeId::of::<T09>(), Box::new(T09::default()));
+    map.insert(TypeId::of::<T10>(), Box::new(T10::default()));
+    map.insert(TypeId::of::<T11>(), Box::new(T11::default()));
+    map.insert(TypeId::of::<T12>(), Box::new(T12::default()));
+    map.insert(TypeId::of::<T13>(), Box::new(T13::default()));
+    map.insert(TypeId::of::<T14>(), Box::new(T14::default()));
+    map.insert(TypeId::of::<T15>(), Box::new(T15::default()));
+    map.insert(TypeId::of::<T16>(), Box::new(T16::default()));
+    map.insert(TypeId::of::<T17>(), Box::new(T17::default()));
+    map.insert(TypeId::of::<T18>(), Box::new(T18::default()));
+    map.insert(TypeId::of::<T19>(), Box::new(T19::default()));
+    map.insert(TypeId::of::<T20>(), Box::new(T20::default()));
+    map.insert(TypeId::of::<T21>(), Box::new(T21::default()));
+    map.insert(TypeId::of::<T22>(), Box::new(T22::default()));
+    map.insert(TypeId::of::<T23>(), Box::new(T23::default()));
+    map.insert(TypeId::of::<T24>(), Box::new(T24::default()));
+    map.insert(TypeId::of::<T25>(), Box::new(T25::default()));
+    map.insert(TypeId::of::<T26>(), Box::new(T26::default()));
+    map.insert(TypeId::of::<T27>(), Box::new(T27::default()));
+    map.insert(TypeId::of::<T28>(), Box::new(T28::default()));
+    map.insert(TypeId::of::<T29>(), Box::new(T29::default()));
+    map.insert(TypeId::of::<T30>(), Box::new(T30::default()));
+    map.insert(TypeId::of::<T31>(), Box::new(T31::default()));
+    map
+}
+
+fn insert(c: &mut Criterion) {
+    c.bench_function("TypeSet (HashMap + pass-through hasher) insert x32", |b| {
+        b.iter(fill_type_set);
+    });
+    c.bench_function("BTreeMap<TypeId, _> insert x32", |b| {
+        b.iter(fill_btree_map);
+    });
+}
+
+fn lookup(c: &mut Criterion) {
+    let set = fill_type_set();
+    c.bench_function("TypeSet (HashMap + pass-through hasher) get x4", |b| {
+        b.iter(|| {
+            criterion::black_box((
+                set.get::<T00>(),
+                set.get::<T15>(),
+                set.get::<T31>(),
+                set.get::<T07>(),
+            ))
+        });
+    });
+
+    let map = fill_btree_map();
+    c.bench_function("BTreeMap<TypeId, _> get x4", |b| {
+        b.iter(|| {
+            criterion::black_box((
+                map.get(&TypeId::of::<T00>()),
+                map.get(&TypeId::of::<T15>()),
+                map.get(&TypeId::of::<T31>()),
+                map.get(&TypeId::of::<T07>()),
+            ))
+        });
+    });
+}
+
+criterion_group!(benches, insert, lookup);
+criterion_main!(benches);
@@ -0,0 +1,109 @@
+use crate::{value::Value, TypeSet};
+use allocator_api2::alloc::{Allocator, Global};
+use std::any::TypeId;
+
+/// A [`TypeSet`] variant that can be [`Clone`]d, at the cost of requiring every inserted value to
+/// be `Clone` as well.
+///
+/// `TypeSet` itself can't implement `Clone` because values are type-erased behind a boxed
+/// `dyn Any`. `ClonableTypeSet` works around this by capturing a monomorphized clone thunk for
+/// each value at insertion time (see [`ClonableTypeSet::insert`]); cloning the set just invokes
+/// each stored thunk in turn.
+///
+/// ## Example
+///
+/// ```rust
+/// use type_set::ClonableTypeSet;
+///
+/// let set = ClonableTypeSet::new().with(8u8).with(String::from("hello"));
+/// let cloned = set.clone();
+/// assert_eq!(cloned.get::<u8>(), Some(&8));
+/// assert_eq!(cloned.get::<String>(), Some(&String::from("hello")));
+/// ```
+pub struct ClonableTypeSet<A: Allocator + Clone = Global>(TypeSet<A>);
+
+impl Default for ClonableTypeSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ClonableTypeSet {
+    /// Constructs a new, empty `ClonableTypeSet` using the global allocator.
+    #[must_use]
+    pub fn new() -> Self {
+        Self(TypeSet::new())
+    }
+}
+
+impl<A: Allocator + Clone> ClonableTypeSet<A> {
+    /// Constructs a new, empty `ClonableTypeSet` that allocates its backing storage with `alloc`.
+    #[must_use]
+    pub fn new_in(alloc: A) -> Self {
+        Self(TypeSet::new_in(alloc))
+    }
+
+    /// Builder-style method that inserts `value` and returns `self`, for chaining.
+    #[must_use]
+    pub fn with<T: Clone + Send + Sync + 'static>(mut self, value: T) -> Self {
+        self.insert(value);
+        self
+    }
+
+    /// Inserts a value into the set, returning the previously-stored value of this type, if any.
+    ///
+    /// Unlike [`TypeSet::insert`], this records a clone thunk for `T` alongside the value so that
+    /// [`ClonableTypeSet::clone`] can reproduce it later.
+    pub fn insert<T: Clone + Send + Sync + 'static>(&mut self, value: T) -> Option<T> {
+        let key = TypeId::of::<T>();
+        let previous = self.0.map.insert(key, Value::new_cloneable(value));
+        previous.map(|value| crate::unwrap!(value.downcast()))
+    }
+
+    /// Returns a reference to the stored value of type `T`, if any.
+    #[must_use]
+    pub fn get<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        self.0.get()
+    }
+
+    /// Returns a mutable reference to the stored value of type `T`, if any.
+    #[must_use]
+    pub fn get_mut<T: Send + Sync + 'static>(&mut self) -> Option<&mut T> {
+        self.0.get_mut()
+    }
+
+    /// Removes and returns the stored value of type `T`, if any.
+    pub fn remove<T: Send + Sync + 'static>(&mut self) -> Option<T> {
+        self.0.remove()
+    }
+
+    /// Returns whether a value of type `T` is currently stored in this set.
+    #[must_use]
+    pub fn contains<T: Send + Sync + 'static>(&self) -> bool {
+        self.0.contains::<T>()
+    }
+
+    /// Returns the number of values stored in this set.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether this set contains no values.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<A: Allocator + Clone> Clone for ClonableTypeSet<A> {
+    fn clone(&self) -> Self {
+        let mut cloned = TypeSet::new_in(self.0.map.allocator().clone());
+        for (key, value) in &self.0.map {
+            if let Some(value) = value.clone_value() {
+                cloned.map.insert(*key, value);
+            }
+        }
+        Self(cloned)
+    }
+}
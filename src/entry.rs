@@ -1,8 +1,10 @@
-use crate::{unwrap, Key, Value};
+use crate::{hasher::PassThroughBuildHasher, unwrap, Key, Value};
+use allocator_api2::alloc::{Allocator, Global};
+use hashbrown::hash_map;
 use std::{
     any::{type_name, Any, TypeId},
-    collections::btree_map,
     fmt::{self, Debug, Formatter},
+    hash::BuildHasher,
     marker::PhantomData,
     ops::{Deref, DerefMut},
 };
@@ -34,15 +36,15 @@ use std::{
 /// assert_eq!(previous, Some("hello"));
 /// assert_eq!(*current, "entry was occupied");
 /// ```
-pub enum Entry<'a, T> {
+pub enum Entry<'a, T, S = PassThroughBuildHasher, A: Allocator = Global> {
     /// A view into the location a T would be stored in the `TypeSet`. See [`VacantEntry`]
-    Vacant(VacantEntry<'a, T>),
+    Vacant(VacantEntry<'a, T, S, A>),
 
     /// A view into the location a T is currently stored in the `TypeSet`. See [`OccupiedEntry`]
-    Occupied(OccupiedEntry<'a, T>),
+    Occupied(OccupiedEntry<'a, T, S, A>),
 }
 
-impl<'a, T: Debug + Any + Send + Sync + 'static> Debug for Entry<'a, T> {
+impl<'a, T: Debug + Any + Send + Sync + 'static, S, A: Allocator> Debug for Entry<'a, T, S, A> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             Self::Vacant(vacant_entry) => f.debug_tuple("Vacant").field(vacant_entry).finish(),
@@ -56,23 +58,27 @@ impl<'a, T: Debug + Any + Send + Sync + 'static> Debug for Entry<'a, T> {
 /// A view into a vacant entry in a `TypeSet`.
 ///
 /// It is part of the [`Entry`] enum.
-pub struct VacantEntry<'a, T>(
-    pub(super) btree_map::VacantEntry<'a, Key, Value>,
+pub struct VacantEntry<'a, T, S = PassThroughBuildHasher, A: Allocator = Global>(
+    pub(super) hash_map::VacantEntry<'a, Key, Value, S, A>,
     PhantomData<T>,
 );
 
-impl<'a, T: Debug + Any + Send + Sync + 'static> Debug for VacantEntry<'a, T> {
+impl<'a, T: Debug + Any + Send + Sync + 'static, S, A: Allocator> Debug
+    for VacantEntry<'a, T, S, A>
+{
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "VacantEntry<{}>", type_name::<T>())
     }
 }
 /// A view into the location a T is stored
-pub struct OccupiedEntry<'a, T>(
-    pub(super) btree_map::OccupiedEntry<'a, Key, Value>,
+pub struct OccupiedEntry<'a, T, S = PassThroughBuildHasher, A: Allocator = Global>(
+    pub(super) hash_map::OccupiedEntry<'a, Key, Value, S, A>,
     PhantomData<T>,
 );
 
-impl<'a, T: Debug + Any + Send + Sync + 'static> Debug for OccupiedEntry<'a, T> {
+impl<'a, T: Debug + Any + Send + Sync + 'static, S, A: Allocator> Debug
+    for OccupiedEntry<'a, T, S, A>
+{
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_tuple(&format!("OccupiedEntry<{}>", type_name::<T>()))
             .field(unwrap!(self.0.get().downcast_ref::<T>()))
@@ -80,7 +86,7 @@ impl<'a, T: Debug + Any + Send + Sync + 'static> Debug for OccupiedEntry<'a, T>
     }
 }
 
-impl<'a, T: Send + Sync + 'static> Entry<'a, T> {
+impl<'a, T: Send + Sync + 'static, S: BuildHasher, A: Allocator> Entry<'a, T, S, A> {
     /// Ensures a value is in the `Entry` by inserting the provided `default` value if the Entry was
     /// previously vacant. Returns a mutable reference to the value.
     ///
@@ -164,7 +170,7 @@ impl<'a, T: Send + Sync + 'static> Entry<'a, T> {
     ///
     /// This function will panic if the entry is vacant
     #[must_use]
-    pub fn unwrap_occupied(self) -> OccupiedEntry<'a, T> {
+    pub fn unwrap_occupied(self) -> OccupiedEntry<'a, T, S, A> {
         self.into_occupied().unwrap_or_else(|| {
             panic!(
                 "expected an occupied type-set entry for {}, but was vacant",
@@ -179,7 +185,7 @@ impl<'a, T: Send + Sync + 'static> Entry<'a, T> {
     ///
     /// This function will panic if the entry is occupied
     #[must_use]
-    pub fn unwrap_vacant(self) -> VacantEntry<'a, T> {
+    pub fn unwrap_vacant(self) -> VacantEntry<'a, T, S, A> {
         self.into_vacant().unwrap_or_else(|| {
             panic!(
                 "expected a vacant type-set entry for {}, but was occupied",
@@ -196,7 +202,7 @@ impl<'a, T: Send + Sync + 'static> Entry<'a, T> {
 
     /// Returns an [`OccupiedEntry`] or `None` if this entry is vacant.
     #[must_use]
-    pub fn into_occupied(self) -> Option<OccupiedEntry<'a, T>> {
+    pub fn into_occupied(self) -> Option<OccupiedEntry<'a, T, S, A>> {
         match self {
             Entry::Occupied(occupied_entry) => Some(occupied_entry),
             Entry::Vacant(_) => None,
@@ -205,7 +211,7 @@ impl<'a, T: Send + Sync + 'static> Entry<'a, T> {
 
     /// Returns a [`VacantEntry`] or `None` if this entry is occupied.
     #[must_use]
-    pub fn into_vacant(self) -> Option<VacantEntry<'a, T>> {
+    pub fn into_vacant(self) -> Option<VacantEntry<'a, T, S, A>> {
         match self {
             Entry::Occupied(_) => None,
             Entry::Vacant(vacant_entry) => Some(vacant_entry),
@@ -239,17 +245,42 @@ impl<'a, T: Send + Sync + 'static> Entry<'a, T> {
         }
     }
 
-    pub(super) fn new(entry: btree_map::Entry<'a, TypeId, Value>) -> Self {
+    /// Insert a value into this [`Entry`], returning an [`OccupiedEntry`] for the newly-inserted
+    /// value rather than a bare reference, so that it can be immediately operated on further
+    /// (for example with [`OccupiedEntry::get`], [`OccupiedEntry::remove`], or
+    /// [`OccupiedEntry::into_mut`]).
+    ///
+    /// If the Entry is already an [`OccupiedEntry`], the previously contained value is replaced
+    /// and dropped.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let mut set = type_set::TypeSet::new();
+    /// let occupied = set.entry().insert_entry("hello");
+    /// assert_eq!(*occupied.get(), "hello");
+    /// ```
+    pub fn insert_entry(self, value: T) -> OccupiedEntry<'a, T, S, A> {
+        match self {
+            Entry::Vacant(v) => v.insert_entry(value),
+            Entry::Occupied(mut o) => {
+                o.insert(value);
+                o
+            }
+        }
+    }
+
+    pub(super) fn new(entry: hash_map::Entry<'a, TypeId, Value, S, A>) -> Self {
         match entry {
-            btree_map::Entry::Vacant(vacant) => Self::Vacant(VacantEntry(vacant, PhantomData)),
-            btree_map::Entry::Occupied(occupied) => {
+            hash_map::Entry::Vacant(vacant) => Self::Vacant(VacantEntry(vacant, PhantomData)),
+            hash_map::Entry::Occupied(occupied) => {
                 Self::Occupied(OccupiedEntry(occupied, PhantomData))
             }
         }
     }
 }
 
-impl<'a, T: Default + Send + Sync + 'static> Entry<'a, T> {
+impl<'a, T: Default + Send + Sync + 'static, S: BuildHasher, A: Allocator> Entry<'a, T, S, A> {
     /// Ensures a value is in the Entry by inserting the default value if vacant, and returns a
     /// mutable reference to the value.
     ///
@@ -270,14 +301,26 @@ impl<'a, T: Default + Send + Sync + 'static> Entry<'a, T> {
     }
 }
 
-impl<'a, T: Send + Sync + 'static> VacantEntry<'a, T> {
+impl<'a, T: Send + Sync + 'static, S: BuildHasher, A: Allocator> VacantEntry<'a, T, S, A> {
     /// Sets the value of this entry to the provided `value`
     pub fn insert(self, value: T) -> &'a mut T {
         unwrap!(self.0.insert(Value::new(value)).downcast_mut())
     }
+
+    /// Sets the value of this entry to the provided `value`, and returns an [`OccupiedEntry`]
+    /// for the newly-inserted value.
+    pub fn insert_entry(self, value: T) -> OccupiedEntry<'a, T, S, A> {
+        OccupiedEntry(self.0.insert_entry(Value::new(value)), PhantomData)
+    }
+
+    /// Returns the [`TypeId`] that this entry would occupy if a value were inserted.
+    #[must_use]
+    pub fn key(&self) -> TypeId {
+        *self.0.key()
+    }
 }
 
-impl<'a, T: Send + Sync + 'static> OccupiedEntry<'a, T> {
+impl<'a, T: Send + Sync + 'static, S: BuildHasher, A: Allocator> OccupiedEntry<'a, T, S, A> {
     /// Gets a reference to the value in this entry
     #[must_use]
     pub fn get(&self) -> &T {
@@ -304,6 +347,19 @@ impl<'a, T: Send + Sync + 'static> OccupiedEntry<'a, T> {
         unwrap!(self.0.remove().downcast())
     }
 
+    /// Returns the [`TypeId`] stored in this entry.
+    #[must_use]
+    pub fn key(&self) -> TypeId {
+        *self.0.key()
+    }
+
+    /// Take ownership of the [`TypeId`] and value from this Entry
+    #[must_use]
+    pub fn remove_entry(self) -> (TypeId, T) {
+        let (key, value) = self.0.remove_entry();
+        (key, unwrap!(value.downcast()))
+    }
+
     /// Converts the entry into a mutable reference to its value.
     ///
     /// If you need multiple references to the `OccupiedEntry`, see [`OccupiedEntry::get_mut`].
@@ -313,7 +369,9 @@ impl<'a, T: Send + Sync + 'static> OccupiedEntry<'a, T> {
     }
 }
 
-impl<'a, T: Send + Sync + 'static> Deref for OccupiedEntry<'a, T> {
+impl<'a, T: Send + Sync + 'static, S: BuildHasher, A: Allocator> Deref
+    for OccupiedEntry<'a, T, S, A>
+{
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
@@ -321,20 +379,26 @@ impl<'a, T: Send + Sync + 'static> Deref for OccupiedEntry<'a, T> {
     }
 }
 
-impl<'a, T: Send + Sync + 'static> DerefMut for OccupiedEntry<'a, T> {
+impl<'a, T: Send + Sync + 'static, S: BuildHasher, A: Allocator> DerefMut
+    for OccupiedEntry<'a, T, S, A>
+{
     fn deref_mut(&mut self) -> &mut Self::Target {
         self.get_mut()
     }
 }
 
-impl<'a, T: Send + Sync + 'static> From<OccupiedEntry<'a, T>> for Entry<'a, T> {
-    fn from(occupied_entry: OccupiedEntry<'a, T>) -> Self {
+impl<'a, T: Send + Sync + 'static, S: BuildHasher, A: Allocator> From<OccupiedEntry<'a, T, S, A>>
+    for Entry<'a, T, S, A>
+{
+    fn from(occupied_entry: OccupiedEntry<'a, T, S, A>) -> Self {
         Self::Occupied(occupied_entry)
     }
 }
 
-impl<'a, T: Send + Sync + 'static> From<VacantEntry<'a, T>> for Entry<'a, T> {
-    fn from(vacant_entry: VacantEntry<'a, T>) -> Self {
+impl<'a, T: Send + Sync + 'static, S: BuildHasher, A: Allocator> From<VacantEntry<'a, T, S, A>>
+    for Entry<'a, T, S, A>
+{
+    fn from(vacant_entry: VacantEntry<'a, T, S, A>) -> Self {
         Self::Vacant(vacant_entry)
     }
 }
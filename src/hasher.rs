@@ -0,0 +1,46 @@
+use std::hash::{BuildHasher, Hasher};
+
+/// A [`Hasher`] for keying a `TypeSet` by [`TypeId`][std::any::TypeId].
+///
+/// `TypeId` is already an effectively-random 64-bit value, so mixing it through a general-purpose
+/// hash function before using it as a `HashMap` key is pure overhead. This hasher instead just
+/// remembers the last `u64`/`u128` it was given and returns that as the hash, skipping the actual
+/// hashing work entirely.
+///
+/// This is only sound as the `BuildHasher` for a map keyed on `TypeId`; it is not a general-purpose
+/// `Hasher` and should not be used for anything else.
+#[derive(Default)]
+pub struct PassThroughHasher(u64);
+
+impl Hasher for PassThroughHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut buf = [0u8; 8];
+        let len = bytes.len().min(8);
+        buf[..len].copy_from_slice(&bytes[..len]);
+        self.0 = u64::from_ne_bytes(buf);
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.0 = i;
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.0 = i as u64;
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Builds [`PassThroughHasher`]s for the `HashMap` backing a `TypeSet`.
+#[derive(Default, Clone, Copy)]
+pub struct PassThroughBuildHasher;
+
+impl BuildHasher for PassThroughBuildHasher {
+    type Hasher = PassThroughHasher;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        PassThroughHasher::default()
+    }
+}
@@ -0,0 +1,192 @@
+//! A type-safe, type-indexed collection: a set that holds at most one value of each type it is
+//! given, analogous to a `HashMap<TypeId, Box<dyn Any>>` but with an ergonomic, statically-typed
+//! API.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use type_set::TypeSet;
+//!
+//! let mut set = TypeSet::new().with(8u8).with("hello");
+//! assert_eq!(set.get::<u8>(), Some(&8));
+//! assert_eq!(set.get::<&'static str>(), Some(&"hello"));
+//! assert_eq!(set.get::<u16>(), None);
+//! ```
+
+mod clonable;
+pub mod entry;
+pub mod hasher;
+mod value;
+
+pub use clonable::ClonableTypeSet;
+
+use allocator_api2::alloc::{Allocator, Global};
+use entry::Entry;
+use hasher::PassThroughBuildHasher;
+use hashbrown::HashMap;
+use std::any::TypeId;
+use value::Value;
+
+pub(crate) type Key = TypeId;
+
+macro_rules! unwrap {
+    ($e:expr) => {
+        match $e {
+            Some(value) => value,
+            None => unreachable!("type_set: TypeId collision or internal type-tag mismatch"),
+        }
+    };
+}
+pub(crate) use unwrap;
+
+/// A type-indexed collection holding at most one value of each type.
+///
+/// `TypeSet` is keyed on [`TypeId`] and backed by a [`HashMap`][hashbrown::HashMap] with a
+/// pass-through hasher (since `TypeId` is already effectively a hash, there is no need to hash it
+/// again). See the [`entry`] module for the `Entry` API used by [`TypeSet::entry`].
+///
+/// `TypeSet` is generic over its [`Allocator`], defaulting to [`Global`]; use
+/// [`TypeSet::new_in`] to back a set with a custom allocator.
+///
+/// ## Examples
+///
+/// ```rust
+/// use type_set::TypeSet;
+///
+/// let mut set = TypeSet::new();
+/// set.insert(1u8);
+/// set.insert(String::from("hello"));
+/// assert_eq!(set.get::<u8>(), Some(&1));
+/// assert_eq!(set.get::<String>(), Some(&String::from("hello")));
+/// ```
+pub struct TypeSet<A: Allocator = Global> {
+    map: HashMap<Key, Value, PassThroughBuildHasher, A>,
+}
+
+impl Default for TypeSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TypeSet {
+    /// Constructs a new, empty `TypeSet`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            map: HashMap::with_capacity_and_hasher(0, PassThroughBuildHasher),
+        }
+    }
+}
+
+impl<A: Allocator> TypeSet<A> {
+    /// Constructs a new, empty `TypeSet` that allocates its backing storage with `alloc`.
+    #[must_use]
+    pub fn new_in(alloc: A) -> Self {
+        Self {
+            map: HashMap::with_capacity_and_hasher_in(0, PassThroughBuildHasher, alloc),
+        }
+    }
+
+    /// Builder-style method that inserts `value` and returns `self`, for chaining.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let set = type_set::TypeSet::new().with(8u8).with("hello");
+    /// assert_eq!(set.get::<u8>(), Some(&8));
+    /// ```
+    #[must_use]
+    pub fn with<T: Send + Sync + 'static>(mut self, value: T) -> Self {
+        self.insert(value);
+        self
+    }
+
+    /// Inserts a value into the set, returning the previously-stored value of this type, if any.
+    pub fn insert<T: Send + Sync + 'static>(&mut self, value: T) -> Option<T> {
+        self.entry().insert(value)
+    }
+
+    /// Returns a reference to the stored value of type `T`, if any.
+    #[must_use]
+    pub fn get<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        self.map
+            .get(&TypeId::of::<T>())
+            .map(|value| unwrap!(value.downcast_ref()))
+    }
+
+    /// Returns a mutable reference to the stored value of type `T`, if any.
+    #[must_use]
+    pub fn get_mut<T: Send + Sync + 'static>(&mut self) -> Option<&mut T> {
+        self.map
+            .get_mut(&TypeId::of::<T>())
+            .map(|value| unwrap!(value.downcast_mut()))
+    }
+
+    /// Removes and returns the stored value of type `T`, if any.
+    pub fn remove<T: Send + Sync + 'static>(&mut self) -> Option<T> {
+        self.map
+            .remove(&TypeId::of::<T>())
+            .map(|value| unwrap!(value.downcast()))
+    }
+
+    /// Removes and returns the stored value of type `T`, if any. An alias for [`TypeSet::remove`]
+    /// provided to mirror [`entry::Entry::take`].
+    pub fn take<T: Send + Sync + 'static>(&mut self) -> Option<T> {
+        self.remove()
+    }
+
+    /// Returns whether a value of type `T` is currently stored in this set.
+    #[must_use]
+    pub fn contains<T: Send + Sync + 'static>(&self) -> bool {
+        self.map.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Returns the number of values stored in this set.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns whether this set contains no values.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Gets the given type's corresponding entry in the set for in-place manipulation. See
+    /// [`entry::Entry`].
+    #[must_use]
+    pub fn entry<T: Send + Sync + 'static>(&mut self) -> Entry<'_, T, PassThroughBuildHasher, A> {
+        Entry::new(self.map.entry(TypeId::of::<T>()))
+    }
+
+    /// Returns a mutable reference to the stored value of type `T`, inserting `default` first if
+    /// it was not already present.
+    pub fn get_or_insert<T: Send + Sync + 'static>(&mut self, default: T) -> &mut T {
+        self.entry().or_insert(default)
+    }
+
+    /// Returns a mutable reference to the stored value of type `T`, inserting the value returned
+    /// by `default` first if it was not already present.
+    pub fn get_or_insert_with<T: Send + Sync + 'static>(
+        &mut self,
+        default: impl FnOnce() -> T,
+    ) -> &mut T {
+        self.entry().or_insert_with(default)
+    }
+
+    /// Returns a mutable reference to the stored value of type `T`, inserting `T::default()`
+    /// first if it was not already present.
+    pub fn get_or_insert_default<T: Default + Send + Sync + 'static>(&mut self) -> &mut T {
+        self.entry().or_default()
+    }
+
+    /// Moves every value out of `other` and into `self`, overwriting any values of the same type
+    /// already in `self`.
+    pub fn merge(&mut self, other: Self) {
+        for (key, value) in other.map {
+            self.map.insert(key, value);
+        }
+    }
+}
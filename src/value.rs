@@ -0,0 +1,49 @@
+use std::any::Any;
+
+/// A type-erased, boxed value stored in a `TypeSet`.
+///
+/// Plain [`Value::new`] values carry no clone support; [`Value::new_cloneable`] additionally
+/// captures a monomorphized function pointer that can downcast back to the concrete `T`, clone
+/// it, and re-box the result, so that a [`ClonableTypeSet`][crate::ClonableTypeSet] can clone
+/// itself without knowing the concrete types it holds.
+pub(crate) struct Value {
+    inner: Box<dyn Any + Send + Sync>,
+    clone_fn: Option<fn(&(dyn Any + Send + Sync)) -> Value>,
+}
+
+impl Value {
+    pub(crate) fn new<T: Send + Sync + 'static>(value: T) -> Self {
+        Self {
+            inner: Box::new(value),
+            clone_fn: None,
+        }
+    }
+
+    pub(crate) fn new_cloneable<T: Clone + Send + Sync + 'static>(value: T) -> Self {
+        fn clone_thunk<T: Clone + Send + Sync + 'static>(any: &(dyn Any + Send + Sync)) -> Value {
+            Value::new_cloneable(any.downcast_ref::<T>().unwrap().clone())
+        }
+
+        Self {
+            inner: Box::new(value),
+            clone_fn: Some(clone_thunk::<T>),
+        }
+    }
+
+    pub(crate) fn downcast<T: 'static>(self) -> Option<T> {
+        self.inner.downcast::<T>().ok().map(|value| *value)
+    }
+
+    pub(crate) fn downcast_ref<T: 'static>(&self) -> Option<&T> {
+        self.inner.downcast_ref::<T>()
+    }
+
+    pub(crate) fn downcast_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.inner.downcast_mut::<T>()
+    }
+
+    /// Returns a clone of this value if it was inserted through [`Value::new_cloneable`].
+    pub(crate) fn clone_value(&self) -> Option<Value> {
+        self.clone_fn.map(|clone_fn| clone_fn(&*self.inner))
+    }
+}
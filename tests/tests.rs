@@ -1,4 +1,4 @@
-use typeset::{entry::Entry, TypeSet};
+use type_set::{entry::Entry, ClonableTypeSet, TypeSet};
 #[test]
 fn smoke() {
     let mut set = TypeSet::new();
@@ -47,17 +47,21 @@ fn merge() {
 
 #[test]
 fn entry() {
+    use std::any::TypeId;
+
     let mut set = TypeSet::new();
     let entry = set.entry::<String>();
     let Entry::Vacant(vacant_entry) = entry else {
         panic!()
     };
+    assert_eq!(vacant_entry.key(), TypeId::of::<String>());
     vacant_entry.insert("hello".into());
 
     let mut entry = set.entry::<String>();
     let Entry::Occupied(occupied_entry) = &mut entry else {
         panic!()
     };
+    assert_eq!(occupied_entry.key(), TypeId::of::<String>());
     assert_eq!(&**occupied_entry, "hello"); //deref
     assert_eq!(occupied_entry.get(), "hello");
     occupied_entry.get_mut().push_str(" world");
@@ -66,7 +70,15 @@ fn entry() {
     let Entry::Occupied(occupied_entry) = entry else {
         panic!()
     };
-    assert_eq!(occupied_entry.remove(), "HELLO WORLD");
+    let (key, value) = occupied_entry.remove_entry();
+    assert_eq!(key, TypeId::of::<String>());
+    assert_eq!(value, "HELLO WORLD");
+
+    set.insert(String::from("hello again"));
+    let Entry::Occupied(occupied_entry) = set.entry::<String>() else {
+        panic!()
+    };
+    assert_eq!(occupied_entry.remove(), "hello again");
 
     assert_eq!(*set.entry::<usize>().or_insert(10), 10);
     assert_eq!(
@@ -82,4 +94,86 @@ fn entry() {
             .or_insert_with(|| String::from("hello")),
         "hello"
     );
+}
+
+#[test]
+fn insert_entry_replaces_occupied() {
+    let mut set = TypeSet::new();
+
+    let occupied = set.entry().insert_entry("hello");
+    assert_eq!(*occupied.get(), "hello");
+
+    let Entry::Occupied(occupied) = set.entry::<&'static str>() else {
+        panic!()
+    };
+    assert_eq!(occupied.get(), &"hello");
+
+    let occupied = set.entry().insert_entry("world");
+    assert_eq!(*occupied.get(), "world");
+    assert_eq!(occupied.remove(), "world");
+    assert_eq!(set.len(), 0);
+}
+
+/// An [`Allocator`] that isn't [`Global`][allocator_api2::alloc::Global], so that tests can
+/// confirm `TypeSet::new_in`/`ClonableTypeSet::new_in` actually route their allocations through
+/// whatever allocator they're given rather than silently falling back to the default. Delegates
+/// to `Global` for the actual memory, but counts every allocation it services.
+mod counting_allocator {
+    use allocator_api2::alloc::{AllocError, Allocator, Global};
+    use std::{alloc::Layout, cell::Cell, ptr::NonNull, rc::Rc};
+
+    #[derive(Clone, Default)]
+    pub(crate) struct CountingAllocator(Rc<Cell<usize>>);
+
+    impl CountingAllocator {
+        pub(crate) fn allocation_count(&self) -> usize {
+            self.0.get()
+        }
+    }
+
+    unsafe impl Allocator for CountingAllocator {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            self.0.set(self.0.get() + 1);
+            Global.allocate(layout)
+        }
+
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            unsafe { Global.deallocate(ptr, layout) }
+        }
+    }
+}
+use counting_allocator::CountingAllocator;
+
+#[test]
+fn new_in_custom_allocator() {
+    let alloc = CountingAllocator::default();
+    let mut set = TypeSet::new_in(alloc.clone());
+
+    set.insert(1u8);
+    set.insert(String::from("hello"));
+    assert_eq!(*set.entry::<u16>().or_insert(2), 2);
+    assert!(alloc.allocation_count() > 0);
+
+    assert_eq!(set.get::<u8>(), Some(&1));
+    assert_eq!(set.get::<String>(), Some(&String::from("hello")));
+    assert_eq!(set.remove::<String>(), Some(String::from("hello")));
+
+    drop(set);
+}
+
+#[test]
+fn clonable_new_in_custom_allocator() {
+    let alloc = CountingAllocator::default();
+    let mut set = ClonableTypeSet::new_in(alloc.clone());
+
+    set.insert(1u8);
+    set.insert(String::from("hello"));
+    assert!(alloc.allocation_count() > 0);
+
+    let cloned = set.clone();
+    assert_eq!(cloned.get::<u8>(), Some(&1));
+    assert_eq!(cloned.get::<String>(), Some(&String::from("hello")));
+
+    drop(set);
+    drop(cloned);
 }
\ No newline at end of file